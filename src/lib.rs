@@ -1,11 +1,158 @@
 use near_sdk::{near, env, AccountId, Promise, CryptoHash, NearToken, PanicOnDefault, Gas, GasWeight, PromiseError};
-use near_sdk_contract_tools::{event, standard::nep297::Event};
+use near_sdk::borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk_contract_tools::{event, standard::nep297::Event, owner::Owner, rbac::Rbac, Owner, Rbac};
 // use near_sdk::serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use serde_json::json;
 
+// Roles gating the AVS lifecycle: `AttestationCenter` drives task submission/response, while
+// `ModelAdmin` may be delegated model-registry management without full contract ownership.
+#[derive(BorshSerialize, BorshDeserialize, Clone)]
+pub enum Role {
+    AttestationCenter,
+    ModelAdmin,
+}
+
 const YIELD_REGISTER: u64 = 0;
 
+// Standard alt_bn128 G2 generator (EIP-197), encoded as x.c0 | x.c1 | y.c0 | y.c1, big-endian.
+const G2_GENERATOR: [u8; 128] = [
+    0x18, 0x00, 0xde, 0xef, 0x12, 0x1f, 0x1e, 0x76, 0x42, 0x6a, 0x00, 0x66, 0x5e, 0x5c, 0x44, 0x79,
+    0x67, 0x43, 0x22, 0xd4, 0xf7, 0x5e, 0xda, 0xdd, 0x46, 0xde, 0xbd, 0x5c, 0xd9, 0x92, 0xf6, 0xed,
+    0x19, 0x8e, 0x93, 0x93, 0x92, 0x0d, 0x48, 0x3a, 0x72, 0x60, 0xbf, 0xb7, 0x31, 0xfb, 0x5d, 0x25,
+    0xf1, 0xaa, 0x49, 0x33, 0x35, 0xa9, 0xe7, 0x12, 0x97, 0xe4, 0x85, 0xb7, 0xae, 0xf3, 0x12, 0xc2,
+    0x12, 0xc8, 0x5e, 0xa5, 0xdb, 0x8c, 0x6d, 0xeb, 0x4a, 0xab, 0x71, 0x80, 0x8d, 0xcb, 0x40, 0x8f,
+    0xe3, 0xd1, 0xe7, 0x69, 0x0c, 0x43, 0xd3, 0x7b, 0x4c, 0xe6, 0xcc, 0x01, 0x66, 0xfa, 0x7d, 0xaa,
+    0x09, 0x06, 0x89, 0xd0, 0x58, 0x5f, 0xf0, 0x75, 0xec, 0x9e, 0x99, 0xad, 0x69, 0x0c, 0x33, 0x95,
+    0xbc, 0x4b, 0x31, 0x33, 0x70, 0xb3, 0x8e, 0xf3, 0x55, 0xac, 0xda, 0xdc, 0xd1, 0x22, 0x97, 0x5b,
+];
+
+// BN254 base field modulus, big-endian. Used to negate a G2 point's y-coordinate (plain field
+// subtraction, not curve arithmetic) ahead of a multi-term pairing check, and by the Fp helpers
+// below to reduce sums and to reject out-of-range hash-to-curve candidates.
+const BN254_FIELD_MODULUS: [u8; 32] = [
+    0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81, 0x58, 0x5d,
+    0x97, 0x81, 0x6a, 0x91, 0x68, 0x71, 0xca, 0x8d, 0x3c, 0x20, 0x8c, 0x16, 0xd8, 0x7c, 0xfd, 0x47,
+];
+
+// Negates a 32-byte big-endian Fp element modulo the BN254 base field.
+fn fp_negate(value: &[u8]) -> [u8; 32] {
+    if value.iter().all(|&b| b == 0) {
+        return [0u8; 32];
+    }
+    let mut result = [0u8; 32];
+    let mut borrow: i32 = 0;
+    for i in (0..32).rev() {
+        let diff = BN254_FIELD_MODULUS[i] as i32 - value[i] as i32 - borrow;
+        if diff < 0 {
+            result[i] = (diff + 256) as u8;
+            borrow = 1;
+        } else {
+            result[i] = diff as u8;
+            borrow = 0;
+        }
+    }
+    result
+}
+
+// Negates a 128-byte G2 point (x.c0 | x.c1 | y.c0 | y.c1) by negating its y-coordinate.
+fn g2_negate(point: &[u8]) -> [u8; 128] {
+    let mut negated = [0u8; 128];
+    negated[0..64].copy_from_slice(&point[0..64]);
+    negated[64..96].copy_from_slice(&fp_negate(&point[64..96]));
+    negated[96..128].copy_from_slice(&fp_negate(&point[96..128]));
+    negated
+}
+
+// (p+1)/4 for the BN254 base field, where p ≡ 3 (mod 4) — lets a quadratic residue's square
+// root be computed directly as a^((p+1)/4) mod p via modular exponentiation.
+const BN254_SQRT_EXPONENT: [u8; 32] = [
+    0x0c, 0x19, 0x13, 0x9c, 0xb8, 0x4c, 0x68, 0x0a, 0x6e, 0x14, 0x11, 0x6d, 0xa0, 0x60, 0x56, 0x17,
+    0x65, 0xe0, 0x5a, 0xa4, 0x5a, 0x1c, 0x72, 0xa3, 0x4f, 0x08, 0x23, 0x05, 0xb6, 0x1f, 0x3f, 0x52,
+];
+
+const FP_ONE: [u8; 32] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1,
+];
+
+// The `b` coefficient of the BN254 G1 curve equation y² = x³ + b.
+const CURVE_B: [u8; 32] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3,
+];
+
+// True if `a >= b`, comparing as big-endian unsigned integers.
+fn bytes_ge(a: &[u8; 32], b: &[u8; 32]) -> bool {
+    for i in 0..32 {
+        if a[i] != b[i] {
+            return a[i] > b[i];
+        }
+    }
+    true
+}
+
+// Subtracts `b` from `a`, assuming `a >= b`. Big-endian, same borrow chain as `fp_negate`.
+fn bytes_sub(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let mut result = [0u8; 32];
+    let mut borrow: i32 = 0;
+    for i in (0..32).rev() {
+        let diff = a[i] as i32 - b[i] as i32 - borrow;
+        if diff < 0 {
+            result[i] = (diff + 256) as u8;
+            borrow = 1;
+        } else {
+            result[i] = diff as u8;
+            borrow = 0;
+        }
+    }
+    result
+}
+
+// Adds two already-reduced Fp elements mod the BN254 base field. `a + b` is always below `2p`,
+// so at most one subtraction is needed to bring the result back under `p`.
+fn fp_add(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let mut sum = [0u8; 32];
+    let mut carry: u16 = 0;
+    for i in (0..32).rev() {
+        let s = a[i] as u16 + b[i] as u16 + carry;
+        sum[i] = (s & 0xff) as u8;
+        carry = s >> 8;
+    }
+    if bytes_ge(&sum, &BN254_FIELD_MODULUS) {
+        bytes_sub(&sum, &BN254_FIELD_MODULUS)
+    } else {
+        sum
+    }
+}
+
+// Multiplies two Fp elements mod the BN254 base field via binary long multiplication: double the
+// running total for each bit of `b` (MSB to LSB), folding in `a` whenever that bit is set.
+fn fp_mul(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let mut result = [0u8; 32];
+    for byte in b.iter() {
+        for bit in (0..8).rev() {
+            result = fp_add(&result, &result);
+            if (byte >> bit) & 1 == 1 {
+                result = fp_add(&result, a);
+            }
+        }
+    }
+    result
+}
+
+// Raises an Fp element to `exponent` mod the BN254 base field via square-and-multiply.
+fn fp_pow(base: &[u8; 32], exponent: &[u8; 32]) -> [u8; 32] {
+    let mut result = FP_ONE;
+    for byte in exponent.iter() {
+        for bit in (0..8).rev() {
+            result = fp_mul(&result, &result);
+            if (byte >> bit) & 1 == 1 {
+                result = fp_mul(&result, base);
+            }
+        }
+    }
+    result
+}
+
 // Structure to hold information about model and reward balance
 #[derive(Clone)]
 #[derive(serde::Serialize)]
@@ -20,14 +167,140 @@ pub enum Response {
     TimeOutError,
 }
 
+// The actual inference request an operator should run off-chain, carried through the yield so
+// the NEP-297 event stream is actionable instead of a placeholder string pair.
+#[near(serializers = [json])]
+pub struct InferenceRequest {
+    pub model_name: String,
+    pub prompt: String,
+}
+
+// Structured failure reasons, panicked via `AvsError::panic` behind a stable "CODE: detail"
+// string so relayers and front-ends can branch on the exact failure instead of scraping
+// ad-hoc panic messages.
+#[derive(Debug, Clone)]
+pub enum AvsError {
+    ReadRegisterFailed,
+    InvalidCryptoHash,
+    UnknownModel(String),
+    Unauthorized,
+    QuorumNotMet { required: u32, provided: u32 },
+    OperatorSetMismatch,
+    InvalidPublicKeyLength(usize),
+    EmptyOperatorSet,
+    DuplicateOperator(u128),
+    InvalidSignatureLength(usize),
+    UnregisteredOperator(u128),
+    InvalidSignature,
+    InsufficientStake,
+    NoPendingUnstakeRequest,
+    BondingPeriodNotElapsed,
+}
+
+impl AvsError {
+    fn panic(&self) -> ! {
+        env::panic_str(&format!("{}: {}", self.code(), self))
+    }
+
+    fn code(&self) -> &'static str {
+        match self {
+            AvsError::ReadRegisterFailed => "READ_REGISTER_FAILED",
+            AvsError::InvalidCryptoHash => "INVALID_CRYPTO_HASH",
+            AvsError::UnknownModel(_) => "UNKNOWN_MODEL",
+            AvsError::Unauthorized => "UNAUTHORIZED",
+            AvsError::QuorumNotMet { .. } => "QUORUM_NOT_MET",
+            AvsError::OperatorSetMismatch => "OPERATOR_SET_MISMATCH",
+            AvsError::InvalidPublicKeyLength(_) => "INVALID_PUBLIC_KEY_LENGTH",
+            AvsError::EmptyOperatorSet => "EMPTY_OPERATOR_SET",
+            AvsError::DuplicateOperator(_) => "DUPLICATE_OPERATOR",
+            AvsError::InvalidSignatureLength(_) => "INVALID_SIGNATURE_LENGTH",
+            AvsError::UnregisteredOperator(_) => "UNREGISTERED_OPERATOR",
+            AvsError::InvalidSignature => "INVALID_SIGNATURE",
+            AvsError::InsufficientStake => "INSUFFICIENT_STAKE",
+            AvsError::NoPendingUnstakeRequest => "NO_PENDING_UNSTAKE_REQUEST",
+            AvsError::BondingPeriodNotElapsed => "BONDING_PERIOD_NOT_ELAPSED",
+        }
+    }
+}
+
+impl std::fmt::Display for AvsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AvsError::ReadRegisterFailed => write!(f, "failed to read the yield id from the register"),
+            AvsError::InvalidCryptoHash => write!(f, "register contents are not a valid CryptoHash"),
+            AvsError::UnknownModel(name) => write!(f, "no model registered for '{name}'"),
+            AvsError::Unauthorized => write!(f, "caller is not authorized to perform this action"),
+            AvsError::QuorumNotMet { required, provided } => {
+                write!(f, "quorum not met: {provided} operators provided, {required} required")
+            }
+            AvsError::OperatorSetMismatch => {
+                write!(f, "operator_ids at settlement do not match the quorum verified for this task")
+            }
+            AvsError::InvalidPublicKeyLength(len) => {
+                write!(f, "operator public key must be a 128-byte alt_bn128 G2 point, got {len} bytes")
+            }
+            AvsError::EmptyOperatorSet => write!(f, "operator_ids must not be empty"),
+            AvsError::DuplicateOperator(operator_id) => {
+                write!(f, "duplicate operator id in operator_ids: {operator_id}")
+            }
+            AvsError::InvalidSignatureLength(len) => {
+                write!(f, "tp_signature must be a 64-byte alt_bn128 G1 point, got {len} bytes")
+            }
+            AvsError::UnregisteredOperator(operator_id) => {
+                write!(f, "unregistered operator id: {operator_id}")
+            }
+            AvsError::InvalidSignature => write!(f, "aggregate operator signature failed pairing check"),
+            AvsError::InsufficientStake => write!(f, "insufficient staked balance"),
+            AvsError::NoPendingUnstakeRequest => write!(f, "no pending unstake request"),
+            AvsError::BondingPeriodNotElapsed => write!(f, "bonding period has not elapsed"),
+        }
+    }
+}
+
 #[near(contract_state)]
-#[derive(PanicOnDefault)]
+#[derive(PanicOnDefault, Owner, Rbac)]
+#[rbac(roles = "Role")]
 pub struct AvsLogic {
     attestation_center: AccountId,
     request_id: u64,
     models: HashMap<String, AccountId>, // Map performer address to model and reward
+    operator_keys: HashMap<u128, Vec<u8>>, // operator_id -> alt_bn128 G2 public key (128 bytes, x.c0|x.c1|y.c0|y.c1)
+    operator_accounts: HashMap<u128, AccountId>, // operator_id -> the account that bonds stake for it
+    min_operators: u32, // quorum: minimum number of distinct operators required per submission
+    pending: HashMap<u64, PendingRequest>, // outstanding yields awaiting an off-chain response
+    // proof_of_task -> sorted operator_ids that actually formed the BLS-verified quorum in
+    // `before_task_submission`, so `after_task_submission` can reward/slash only operators who
+    // really participated instead of trusting whatever the attestation center names at settlement.
+    verified_operators: HashMap<String, Vec<u128>>,
+    stakes: HashMap<AccountId, NearToken>, // bonded stake per operator account
+    unstake_requests: HashMap<AccountId, UnstakeRequest>, // pending withdrawals past the bonding period
+    treasury: AccountId, // receives slashed stake
+    min_stake: NearToken, // minimum bond every participating operator must hold for a task's reward to be paid
+    slash_fraction_bps: u32, // fraction of an operator's stake slashed on a rejected task, in basis points
+    bonding_period_ns: u64, // delay between `request_unstake` and a stake becoming withdrawable
+}
+
+// A stake withdrawal queued by `request_unstake`, claimable via `withdraw` once `unlock_at` passes.
+#[derive(Clone, BorshSerialize, BorshDeserialize)]
+pub struct UnstakeRequest {
+    amount: NearToken,
+    unlock_at: u64,
 }
 
+// Tracks a yield created in `before_task_submission` until `return_external_response` resolves it
+// or `purge_expired` reclaims it for having stalled past its timeout.
+#[derive(Clone, BorshSerialize, BorshDeserialize)]
+pub struct PendingRequest {
+    yield_id: CryptoHash,
+    model_name: String,
+    prompt: String,
+    created_at: u64,
+}
+
+// Sentinel fed to `promise_yield_resume` by `purge_expired` so the callback can tell a forced
+// reclamation apart from a genuine off-chain answer and resolve it to `Response::TimeOutError`.
+const TIMEOUT_SENTINEL: &str = "__avs_timeout__";
+
 #[event(version = "1.0.0", standard = "nep297")]
 pub struct AvsEvent {
     pub model_name: String,
@@ -35,35 +308,171 @@ pub struct AvsEvent {
     pub yield_id: CryptoHash
 }
 
+#[event(version = "1.0.0", standard = "nep297")]
+pub struct TimeoutEvent {
+    pub request_id: u64,
+    pub model_name: String,
+    pub prompt: String,
+}
+
+#[event(version = "1.0.0", standard = "nep297")]
+pub struct SlashEvent {
+    pub operator_id: u128,
+    pub account_id: AccountId,
+    pub amount: NearToken,
+}
+
 
 #[near]
 impl AvsLogic {
     #[init]
-    pub fn new(attestation_center: AccountId) -> Self {
-        Self {
+    pub fn new(
+        owner_id: AccountId,
+        attestation_center: AccountId,
+        min_operators: u32,
+        treasury: AccountId,
+        min_stake: NearToken,
+        slash_fraction_bps: u32,
+        bonding_period_ns: u64,
+    ) -> Self {
+        assert!(slash_fraction_bps <= 10_000, "slash_fraction_bps must be a valid basis-point fraction");
+        let mut contract = Self {
             request_id: 0,
-            attestation_center,
-            models: HashMap::new()
+            attestation_center: attestation_center.clone(),
+            models: HashMap::new(),
+            operator_keys: HashMap::new(),
+            operator_accounts: HashMap::new(),
+            min_operators,
+            pending: HashMap::new(),
+            verified_operators: HashMap::new(),
+            stakes: HashMap::new(),
+            unstake_requests: HashMap::new(),
+            treasury,
+            min_stake,
+            slash_fraction_bps,
+            bonding_period_ns,
+        };
+        Owner::init(&mut contract, &owner_id);
+        contract.add_role(attestation_center, &Role::AttestationCenter);
+        contract
+    }
+
+    // Bond NEAR as stake for the calling operator account.
+    #[payable]
+    pub fn stake(&mut self) {
+        let account_id = env::predecessor_account_id();
+        let deposit = env::attached_deposit();
+        let balance = self.stakes.get(&account_id).copied().unwrap_or(NearToken::from_yoctonear(0));
+        self.stakes.insert(account_id, balance.saturating_add(deposit));
+    }
+
+    // Move `amount` out of active stake and start the bonding-period countdown on it. Folds into
+    // any unstake request already pending for the caller (summing the amount, pushing out the
+    // unlock time) instead of overwriting it, so an earlier pending amount can never be clobbered
+    // and silently lost.
+    pub fn request_unstake(&mut self, amount: NearToken) {
+        let account_id = env::predecessor_account_id();
+        let balance = self.stakes.get(&account_id).copied().unwrap_or(NearToken::from_yoctonear(0));
+        if balance < amount {
+            AvsError::InsufficientStake.panic();
+        }
+        self.stakes.insert(account_id.clone(), balance.saturating_sub(amount));
+        let unlock_at = env::block_timestamp() + self.bonding_period_ns;
+        self.unstake_requests
+            .entry(account_id)
+            .and_modify(|pending| {
+                pending.amount = pending.amount.saturating_add(amount);
+                pending.unlock_at = unlock_at;
+            })
+            .or_insert(UnstakeRequest { amount, unlock_at });
+    }
+
+    // Pay out a previously requested unstake once its bonding period has elapsed.
+    pub fn withdraw(&mut self) -> Promise {
+        let account_id = env::predecessor_account_id();
+        let request = self
+            .unstake_requests
+            .get(&account_id)
+            .unwrap_or_else(|| AvsError::NoPendingUnstakeRequest.panic())
+            .clone();
+        if env::block_timestamp() < request.unlock_at {
+            AvsError::BondingPeriodNotElapsed.panic();
+        }
+        self.unstake_requests.remove(&account_id);
+        Promise::new(account_id).transfer(request.amount)
+    }
+
+    // Only the owner may rotate which account drives the task lifecycle.
+    pub fn set_attestation_center(&mut self, new_attestation_center: AccountId) {
+        self.require_owner();
+        self.remove_role(self.attestation_center.clone(), &Role::AttestationCenter);
+        self.add_role(new_attestation_center.clone(), &Role::AttestationCenter);
+        self.attestation_center = new_attestation_center;
+    }
+
+    // Only the owner may delegate model-registry management.
+    pub fn add_model_admin(&mut self, account_id: AccountId) {
+        self.require_owner();
+        self.add_role(account_id, &Role::ModelAdmin);
+    }
+
+    fn assert_called_by_attestation_center(&self) {
+        self.require_role(&Role::AttestationCenter);
+    }
+
+    // Register (or rotate) an operator's alt_bn128 G2 public key (128 bytes) and the account that
+    // bonds stake on its behalf. Keys are kept in G2 and never aggregated on-chain: NEAR's
+    // precompiles can only sum/multiexp G1 points, so `verify_operator_signature` checks one
+    // pairing term per operator instead of summing their keys into a single aggregate key.
+    pub fn register_operator(&mut self, operator_id: u128, account_id: AccountId, pub_key: Vec<u8>) {
+        self.assert_called_by_attestation_center();
+        if pub_key.len() != 128 {
+            AvsError::InvalidPublicKeyLength(pub_key.len()).panic();
         }
+        self.operator_keys.insert(operator_id, pub_key);
+        self.operator_accounts.insert(operator_id, account_id);
     }
 
     pub fn before_task_submission(
         &mut self,
-        _task_definition_id: u16,
+        task_definition_id: u16,
         _performer_addr: AccountId,
-        _proof_of_task: String,
+        proof_of_task: String,
         _is_approved: bool,
-        _tp_signature: Vec<u8>,
+        tp_signature: Vec<u8>,
         _ta_signature: [u128; 2],
-        _operator_ids: Vec<u128>,
+        operator_ids: Vec<u128>,
+        inference_request: InferenceRequest,
     ) {
+        self.assert_called_by_attestation_center();
+        self.verify_operator_signature(
+            task_definition_id,
+            &proof_of_task,
+            &inference_request.model_name,
+            &inference_request.prompt,
+            &tp_signature,
+            &operator_ids,
+        );
+        if !self.models.contains_key(&inference_request.model_name) {
+            AvsError::UnknownModel(inference_request.model_name).panic();
+        }
+
+        let mut verified_ids = operator_ids.clone();
+        verified_ids.sort_unstable();
+        self.verified_operators.insert(proof_of_task.clone(), verified_ids);
+
         self.request_id += 1;
+        let InferenceRequest { model_name, prompt } = inference_request;
         // this will create a unique ID in the YIELD_REGISTER
         let yield_promise = env::promise_yield_create(
             "return_external_response",
-            &json!({ "request_id": self.request_id })
-                .to_string()
-                .into_bytes(),
+            &json!({
+                "request_id": self.request_id,
+                "model_name": model_name,
+                "prompt": prompt,
+            })
+            .to_string()
+            .into_bytes(),
             Gas::from_tgas(5),
             GasWeight::default(),
             YIELD_REGISTER,
@@ -71,26 +480,164 @@ impl AvsLogic {
 
         // load the ID created by the promise_yield_create
         let yield_id: CryptoHash = env::read_register(YIELD_REGISTER)
-            .expect("read_register failed")
+            .unwrap_or_else(|| AvsError::ReadRegisterFailed.panic())
             .try_into()
-            .expect("conversion to CryptoHash failed");
-        // // store the request, so we can delete it later
-        // let request = ModelInfo { yield_id, prompt };
-        // self.requests.insert(self.request_id, request);
+            .unwrap_or_else(|_| AvsError::InvalidCryptoHash.panic());
+
+        // store the request, so we can purge it later if it never gets a `respond`
+        self.pending.insert(
+            self.request_id,
+            PendingRequest {
+                yield_id,
+                model_name: model_name.clone(),
+                prompt: prompt.clone(),
+                created_at: env::block_timestamp(),
+            },
+        );
 
         // Emit an event with the yield_id and the prompt
-        let event = AvsEvent {
-            model_name: "model_name".to_string(),
-            prompt: "prompt".to_string(),
-            yield_id
-        };
+        let event = AvsEvent { model_name, prompt, yield_id };
         event.emit();
-        
+
         // return the yield promise
         env::promise_return(yield_promise);
     }
 
+    // Reclaims yields that never received a `respond` within `max_age_ns`, resuming them with a
+    // sentinel so `return_external_response` resolves them to `Response::TimeOutError`. Gated to
+    // the attestation center, same as `respond`: an unauthenticated caller supplying `max_age_ns`
+    // of their own choosing (e.g. 0) could otherwise force every in-flight yield to time out
+    // before the real off-chain answer ever lands.
+    pub fn purge_expired(&mut self, max_age_ns: u64) {
+        self.assert_called_by_attestation_center();
+        let now = env::block_timestamp();
+        let expired: Vec<u64> = self
+            .pending
+            .iter()
+            .filter(|(_, request)| now.saturating_sub(request.created_at) > max_age_ns)
+            .map(|(request_id, _)| *request_id)
+            .collect();
+
+        for request_id in expired {
+            let request = self.pending.remove(&request_id).unwrap();
+            env::promise_yield_resume(
+                &request.yield_id,
+                &serde_json::to_vec(&TIMEOUT_SENTINEL).unwrap(),
+            );
+            TimeoutEvent {
+                request_id,
+                model_name: request.model_name,
+                prompt: request.prompt,
+            }
+            .emit();
+        }
+    }
+
+    // Verifies the BLS-style signature over the task message, gating task acceptance on a quorum
+    // of registered operators actually having signed off. Panics on any failure so the
+    // yield-create path in `before_task_submission` is never reached for an unauthenticated task.
+    //
+    // Operator keys live in G2 and are never aggregated on-chain (NEAR's alt_bn128 precompiles
+    // only sum/multiexp G1 points, so there is no way to add G2 keys together here). Instead this
+    // checks the multi-term pairing identity
+    //     e(sig, G2_generator) * prod_i e(H(msg), -pubkey_i) == 1
+    // which holds exactly when `sig` is a valid aggregate signature over `msg` by the named
+    // operators, without ever summing a G2 point. `H(msg)` and `sig` are G1 points, matching what
+    // the precompiles can actually validate and scalar-multiply.
+    fn verify_operator_signature(
+        &self,
+        task_definition_id: u16,
+        proof_of_task: &str,
+        model_name: &str,
+        prompt: &str,
+        signature: &[u8],
+        operator_ids: &[u128],
+    ) {
+        if operator_ids.is_empty() {
+            AvsError::EmptyOperatorSet.panic();
+        }
+        if (operator_ids.len() as u32) < self.min_operators {
+            AvsError::QuorumNotMet {
+                required: self.min_operators,
+                provided: operator_ids.len() as u32,
+            }
+            .panic();
+        }
+        let mut sorted_ids = operator_ids.to_vec();
+        sorted_ids.sort_unstable();
+        if let Some(window) = sorted_ids.windows(2).find(|pair| pair[0] == pair[1]) {
+            AvsError::DuplicateOperator(window[0]).panic();
+        }
+        if signature.len() != 64 {
+            AvsError::InvalidSignatureLength(signature.len()).panic();
+        }
+
+        // H(msg) = hash(task_definition_id || proof_of_task || model_name || prompt), mapped onto
+        // G1 by `hash_to_g1` (try-and-increment). Binding model_name/prompt into the hash means
+        // operators are actually attesting to the inference request being routed, not just to an
+        // opaque proof_of_task string.
+        let mut message = task_definition_id.to_le_bytes().to_vec();
+        message.extend_from_slice(proof_of_task.as_bytes());
+        message.extend_from_slice(model_name.as_bytes());
+        message.extend_from_slice(prompt.as_bytes());
+        let message_hash = env::sha256(&message);
+        let hashed_msg = Self::hash_to_g1(&message_hash);
+
+        let mut pairing_input = Vec::with_capacity(192 * (operator_ids.len() + 1));
+        pairing_input.extend_from_slice(signature);
+        pairing_input.extend_from_slice(&G2_GENERATOR);
+        for operator_id in operator_ids {
+            let key = self
+                .operator_keys
+                .get(operator_id)
+                .unwrap_or_else(|| AvsError::UnregisteredOperator(*operator_id).panic());
+            pairing_input.extend_from_slice(&hashed_msg);
+            pairing_input.extend_from_slice(&g2_negate(key));
+        }
+        if !env::alt_bn128_pairing_check(&pairing_input) {
+            AvsError::InvalidSignature.panic();
+        }
+    }
+
+    // Hash-to-curve via try-and-increment onto y² = x³ + 3 (BN254 G1), so H(msg)'s discrete log
+    // relative to G1_GENERATOR is unknown to anyone. The earlier `sha256(msg) * G1_GENERATOR`
+    // construction gave H(msg) a *publicly known* discrete log (sha256(msg) itself): given any one
+    // observed valid (msg, sig) pair, r = sha256(msg')/sha256(msg) mod the group order could scale
+    // that signature into a valid forgery for a completely different msg', with no cooperation
+    // from any operator. G1 has cofactor 1, so any point satisfying the curve equation is already
+    // in the correct group.
+    fn hash_to_g1(message_hash: &[u8]) -> [u8; 64] {
+        let mut counter: u32 = 0;
+        loop {
+            let mut preimage = message_hash.to_vec();
+            preimage.extend_from_slice(&counter.to_be_bytes());
+            let candidate = env::sha256(&preimage);
+            let mut x = [0u8; 32];
+            x.copy_from_slice(&candidate);
+
+            // Reject (rather than reduce) an out-of-range candidate so the distribution of x
+            // over the field isn't biased towards the low end.
+            if bytes_ge(&x, &BN254_FIELD_MODULUS) {
+                counter += 1;
+                continue;
+            }
+
+            let x_squared = fp_mul(&x, &x);
+            let x_cubed = fp_mul(&x_squared, &x);
+            let rhs = fp_add(&x_cubed, &CURVE_B);
+            let y = fp_pow(&rhs, &BN254_SQRT_EXPONENT);
+            if fp_mul(&y, &y) == rhs {
+                let mut point = [0u8; 64];
+                point[0..32].copy_from_slice(&x);
+                point[32..64].copy_from_slice(&y);
+                return point;
+            }
+            counter += 1;
+        }
+    }
+
     pub fn respond(&mut self, yield_id: CryptoHash, response: String) {
+        self.assert_called_by_attestation_center();
         // resume computation with the response
         env::promise_yield_resume(&yield_id, &serde_json::to_vec(&response).unwrap());
     }
@@ -101,127 +648,416 @@ impl AvsLogic {
         request_id: u32,
         #[callback_result] response: Result<String, PromiseError>,
     ) -> Response {
-        // self.requests.remove(&request_id);
+        self.pending.remove(&(request_id as u64));
 
         match response {
+            Ok(answer) if answer == TIMEOUT_SENTINEL => Response::TimeOutError,
             Ok(answer) => Response::Answer(answer),
             Err(_) => Response::TimeOutError,
         }
     }
 
-    // Register a model with its associated reward for a performer
+    // Register a model with its associated reward for a performer. Gated to the owner or an
+    // account the owner has delegated model-admin rights to via `add_model_admin`.
     pub fn register_model(&mut self, model_addr: AccountId, model_name: String, reward: NearToken) {
+        let caller = env::predecessor_account_id();
+        if self.own_get_owner() != Some(caller.clone()) && !self.has_role(caller, &Role::ModelAdmin) {
+            AvsError::Unauthorized.panic();
+        }
         // let model_info = ModelInfo { model_name, reward };
         self.models.insert(model_name.clone(), model_addr);
         env::log_str(&format!("Model registered for {} with reward {}", model_name, reward));
     }
 
-    // Perform inference after task submission and reward the performer
+    // Perform inference after task submission, then reward the performer's model account or slash
+    // the participating operators.
     pub fn after_task_submission(
         &mut self,
         _task_definition_id: u16,
         model_info: ModelInfo,
-        _proof_of_task: String,
-        _is_approved: bool,
+        proof_of_task: String,
+        is_approved: bool,
         _tp_signature: Vec<u8>,
         _ta_signature: [u128; 2],
-        _operator_ids: Vec<u128>,
+        operator_ids: Vec<u128>,
     ) {
+        self.assert_called_by_attestation_center();
+
+        // Reward/slash only the operator set that was actually BLS-verified for this task in
+        // `before_task_submission` — otherwise the attestation center could name any registered
+        // operator at settlement time regardless of whether they participated.
+        let mut settled_ids = operator_ids.clone();
+        settled_ids.sort_unstable();
+        match self.verified_operators.remove(&proof_of_task) {
+            Some(verified_ids) if verified_ids == settled_ids => {}
+            _ => AvsError::OperatorSetMismatch.panic(),
+        }
+
+        if !is_approved {
+            self.slash_operators(&operator_ids);
+            return;
+        }
+
         // Check if the performer is registered with a model
-        if let Some(model_account) = self.models.get(&model_info.model_name) {
+        if let Some(model_account) = self.models.get(&model_info.model_name).cloned() {
             // Simulate the inference process (just log it)
             env::log_str(&format!(
                 "Running inference on model: {} by {}",
                 model_info.model_name, model_account
             ));
-            
+
+            if !self.operators_meet_min_stake(&operator_ids) {
+                env::log_str(&format!(
+                    "Reward withheld for {}: one or more participating operators are below min_stake",
+                    model_info.model_name
+                ));
+                return;
+            }
+
             // Reward the performer (log the reward for now)
             env::log_str(&format!(
                 "Rewarding performer: {} with {} NEAR for using model: {}",
                 model_account, model_info.reward, model_info.model_name
             ));
-            Promise::new(model_account.clone()).transfer(model_info.reward);
+            Promise::new(model_account).transfer(model_info.reward);
         } else {
-            env::log_str(&format!("No model registered for for {}", model_info.model_name));
-        }
-    }
-}
-
-// #[cfg(test)]
-// mod tests {
-//     use super::*;
-//     use near_sdk::test_utils::{VMContextBuilder};
-//     use near_sdk::{testing_env, AccountId, PromiseResult};
-
-//     // Helper function to create a testing environment
-//     fn get_context(signer_account_id: AccountId) -> VMContextBuilder {
-//         let mut builder = VMContextBuilder::new();
-//         builder.signer_account_id(signer_account_id);
-//         builder
-//     }
-
-//     #[test]
-//     fn test_new() {
-//         let account_id = AccountId::new_unchecked("attestation_center.testnet".to_string());
-//         let context = get_context(account_id.clone());
-//         testing_env!(context.build());
-
-//         let contract = AvsLogic::new(account_id.clone());
-//         assert_eq!(contract.attestation_center, account_id);
-//         assert_eq!(contract.request_id, 0);
-//         assert_eq!(contract.models.len(), 0);
-//     }
-
-//     #[test]
-//     fn test_register_model() {
-//         let account_id = AccountId::new_unchecked("attestation_center.testnet".to_string());
-//         let context = get_context(account_id.clone());
-//         testing_env!(context.build());
-
-//         let mut contract = AvsLogic::new(account_id.clone());
-//         let model_addr = AccountId::new_unchecked("model_owner.testnet".to_string());
-
-//         // Register a model
-//         contract.register_model(model_addr.clone(), "my_model".to_string(), NearToken::from(10));
-
-//         // Assert the model is registered
-//         assert_eq!(contract.models.get("my_model").unwrap(), &model_addr);
-//     }
-
-//     #[test]
-//     fn test_before_task_submission() {
-//         let account_id = AccountId::new_unchecked("attestation_center.testnet".to_string());
-//         let context = get_context(account_id.clone());
-//         testing_env!(context.build());
-
-//         let mut contract = AvsLogic::new(account_id.clone());
-//         contract.before_task_submission(1, account_id.clone(), "proof".to_string(), true, vec![], [0, 0], vec![]);
-        
-//         // Ensure request_id increments
-//         assert_eq!(contract.request_id, 1);
-
-//         // Test event emission by checking logs (requires test_env logging features)
-//         // assert!(logs().contains("Running inference on model"));
-//     }
-
-//     #[test]
-//     fn test_after_task_submission_with_registered_model() {
-//         let account_id = AccountId::new_unchecked("attestation_center.testnet".to_string());
-//         let model_account = AccountId::new_unchecked("model_owner.testnet".to_string());
-//         let context = get_context(account_id.clone());
-//         testing_env!(context.build());
-
-//         let mut contract = AvsLogic::new(account_id.clone());
-//         contract.register_model(model_account.clone(), "my_model".to_string(), NearToken::from(10));
-
-//         let model_info = ModelInfo {
-//             model_name: "my_model".to_string(),
-//             reward: NearToken::from(10),
-//         };
-
-//         contract.after_task_submission(1, model_info.clone(), "proof".to_string(), true, vec![], [0, 0], vec![]);
-        
-//         // Check if the logs contain the correct reward transfer (if using logs)
-//         // assert!(logs().contains("Rewarding performer"));
-//     }
-// }
+            AvsError::UnknownModel(model_info.model_name).panic();
+        }
+    }
+
+    fn operators_meet_min_stake(&self, operator_ids: &[u128]) -> bool {
+        operator_ids.iter().all(|operator_id| {
+            self.operator_accounts
+                .get(operator_id)
+                .and_then(|account_id| self.stakes.get(account_id))
+                .is_some_and(|stake| *stake >= self.min_stake)
+        })
+    }
+
+    // Deducts `slash_fraction_bps` of each participating operator's stake and routes it to the
+    // treasury, for a task the attestation center reported as not approved.
+    fn slash_operators(&mut self, operator_ids: &[u128]) {
+        for operator_id in operator_ids {
+            let Some(account_id) = self.operator_accounts.get(operator_id).cloned() else {
+                continue;
+            };
+            let stake_balance = self.stakes.get(&account_id).copied().unwrap_or(NearToken::from_yoctonear(0));
+            let unbonding_balance = self
+                .unstake_requests
+                .get(&account_id)
+                .map(|request| request.amount)
+                .unwrap_or(NearToken::from_yoctonear(0));
+
+            // Slash is computed over active stake plus anything still mid-unbonding, so an
+            // operator can't dodge a slash by calling request_unstake the moment their
+            // operator_id appears in a task: unstake_requests only starts the bonding-period
+            // countdown, it doesn't actually leave the protocol.
+            let total = stake_balance.as_yoctonear() + unbonding_balance.as_yoctonear();
+            let slashed_total = total * self.slash_fraction_bps as u128 / 10_000;
+            if slashed_total == 0 {
+                continue;
+            }
+
+            let from_stake = slashed_total.min(stake_balance.as_yoctonear());
+            let from_unbonding = slashed_total - from_stake;
+
+            if from_stake > 0 {
+                self.stakes.insert(
+                    account_id.clone(),
+                    NearToken::from_yoctonear(stake_balance.as_yoctonear() - from_stake),
+                );
+            }
+            if from_unbonding > 0 {
+                let remaining = unbonding_balance.as_yoctonear() - from_unbonding;
+                if remaining == 0 {
+                    self.unstake_requests.remove(&account_id);
+                } else if let Some(request) = self.unstake_requests.get_mut(&account_id) {
+                    request.amount = NearToken::from_yoctonear(remaining);
+                }
+            }
+
+            let slashed = NearToken::from_yoctonear(slashed_total);
+            Promise::new(self.treasury.clone()).transfer(slashed);
+            SlashEvent { operator_id: *operator_id, account_id, amount: slashed }.emit();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use near_sdk::test_utils::VMContextBuilder;
+    use near_sdk::testing_env;
+
+    // Uncompressed alt_bn128 G1 generator: (x = 1, y = 2), big-endian field elements. Only used
+    // here as a convenient "some other G1 point" fixture for negative tests.
+    const G1_GENERATOR: [u8; 64] = [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2,
+    ];
+
+    fn account(id: &str) -> AccountId {
+        AccountId::new_unchecked(id.to_string())
+    }
+
+    fn get_context(predecessor_account_id: AccountId) -> VMContextBuilder {
+        let mut builder = VMContextBuilder::new();
+        builder.signer_account_id(predecessor_account_id.clone());
+        builder.predecessor_account_id(predecessor_account_id);
+        builder
+    }
+
+    fn new_test_contract() -> AvsLogic {
+        testing_env!(get_context(account("attestation_center.testnet")).build());
+        AvsLogic::new(
+            account("owner.testnet"),
+            account("attestation_center.testnet"),
+            1,
+            account("treasury.testnet"),
+            NearToken::from_yoctonear(0),
+            1_000,
+            0,
+        )
+    }
+
+    // Builds the same message bytes `verify_operator_signature` hashes, so a test can derive a
+    // signature for a chosen secret key without a full BLS signing implementation.
+    fn message_hash(task_definition_id: u16, proof_of_task: &str, model_name: &str, prompt: &str) -> Vec<u8> {
+        let mut message = task_definition_id.to_le_bytes().to_vec();
+        message.extend_from_slice(proof_of_task.as_bytes());
+        message.extend_from_slice(model_name.as_bytes());
+        message.extend_from_slice(prompt.as_bytes());
+        env::sha256(&message)
+    }
+
+    #[test]
+    fn test_hash_to_g1_produces_a_point_on_the_curve() {
+        let hash = message_hash(1, "proof", "my_model", "do the thing");
+        let point = AvsLogic::hash_to_g1(&hash);
+
+        let mut x = [0u8; 32];
+        let mut y = [0u8; 32];
+        x.copy_from_slice(&point[0..32]);
+        y.copy_from_slice(&point[32..64]);
+
+        let lhs = fp_mul(&y, &y);
+        let rhs = fp_add(&fp_mul(&fp_mul(&x, &x), &x), &CURVE_B);
+        assert_eq!(lhs, rhs, "hash_to_g1 output does not satisfy y^2 = x^3 + 3");
+    }
+
+    #[test]
+    fn test_verify_operator_signature_accepts_valid_signature() {
+        let mut contract = new_test_contract();
+        // Secret key 1: the operator's G2 public key is exactly the G2 generator, so a valid
+        // signature over any message is just H(msg) itself (sig = sk * H(msg) = H(msg)).
+        contract.register_operator(0, account("operator_0.testnet"), G2_GENERATOR.to_vec());
+
+        let hash = message_hash(1, "proof", "my_model", "do the thing");
+        let signature = AvsLogic::hash_to_g1(&hash);
+
+        contract.verify_operator_signature(1, "proof", "my_model", "do the thing", &signature, &[0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "INVALID_SIGNATURE")]
+    fn test_verify_operator_signature_rejects_forged_signature() {
+        let mut contract = new_test_contract();
+        contract.register_operator(0, account("operator_0.testnet"), G2_GENERATOR.to_vec());
+
+        // G1_GENERATOR is not H(msg) for this message, so it isn't a valid signature under sk=1.
+        contract.verify_operator_signature(1, "proof", "my_model", "do the thing", &G1_GENERATOR, &[0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "EMPTY_OPERATOR_SET")]
+    fn test_verify_operator_signature_rejects_empty_operator_ids() {
+        let contract = new_test_contract();
+        contract.verify_operator_signature(1, "proof", "my_model", "do the thing", &G1_GENERATOR, &[]);
+    }
+
+    #[test]
+    #[should_panic(expected = "DUPLICATE_OPERATOR")]
+    fn test_verify_operator_signature_rejects_duplicate_operator_ids() {
+        let mut contract = new_test_contract();
+        contract.register_operator(0, account("operator_0.testnet"), G2_GENERATOR.to_vec());
+
+        contract.verify_operator_signature(1, "proof", "my_model", "do the thing", &G1_GENERATOR, &[0, 0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "UNREGISTERED_OPERATOR")]
+    fn test_verify_operator_signature_rejects_unregistered_operator() {
+        let contract = new_test_contract();
+        contract.verify_operator_signature(1, "proof", "my_model", "do the thing", &G1_GENERATOR, &[0]);
+    }
+
+    #[test]
+    fn test_stake_accumulates_across_calls() {
+        let mut contract = new_test_contract();
+        let operator = account("operator_0.testnet");
+
+        testing_env!(get_context(operator.clone())
+            .attached_deposit(NearToken::from_yoctonear(100))
+            .build());
+        contract.stake();
+
+        testing_env!(get_context(operator.clone())
+            .attached_deposit(NearToken::from_yoctonear(50))
+            .build());
+        contract.stake();
+
+        assert_eq!(contract.stakes.get(&operator).copied(), Some(NearToken::from_yoctonear(150)));
+    }
+
+    // Regression test for the fund-loss bug where a second request_unstake() before a withdraw
+    // clobbered the first pending request instead of accumulating into it.
+    #[test]
+    fn test_request_unstake_accumulates_pending_amount() {
+        let mut contract = new_test_contract();
+        let operator = account("operator_0.testnet");
+
+        testing_env!(get_context(operator.clone())
+            .attached_deposit(NearToken::from_yoctonear(100))
+            .build());
+        contract.stake();
+
+        testing_env!(get_context(operator.clone()).build());
+        contract.request_unstake(NearToken::from_yoctonear(50));
+        contract.request_unstake(NearToken::from_yoctonear(20));
+
+        assert_eq!(contract.stakes.get(&operator).copied(), Some(NearToken::from_yoctonear(30)));
+        assert_eq!(
+            contract.unstake_requests.get(&operator).map(|request| request.amount),
+            Some(NearToken::from_yoctonear(70))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "INSUFFICIENT_STAKE")]
+    fn test_request_unstake_rejects_amount_over_balance() {
+        let mut contract = new_test_contract();
+        let operator = account("operator_0.testnet");
+
+        testing_env!(get_context(operator.clone())
+            .attached_deposit(NearToken::from_yoctonear(10))
+            .build());
+        contract.stake();
+
+        testing_env!(get_context(operator).build());
+        contract.request_unstake(NearToken::from_yoctonear(11));
+    }
+
+    #[test]
+    fn test_withdraw_clears_the_accumulated_pending_request() {
+        let mut contract = new_test_contract();
+        let operator = account("operator_0.testnet");
+
+        testing_env!(get_context(operator.clone())
+            .attached_deposit(NearToken::from_yoctonear(100))
+            .build());
+        contract.stake();
+
+        testing_env!(get_context(operator.clone()).build());
+        contract.request_unstake(NearToken::from_yoctonear(50));
+        contract.request_unstake(NearToken::from_yoctonear(20));
+        contract.withdraw();
+
+        assert!(contract.unstake_requests.get(&operator).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "NO_PENDING_UNSTAKE_REQUEST")]
+    fn test_withdraw_rejects_without_a_pending_request() {
+        let mut contract = new_test_contract();
+        testing_env!(get_context(account("operator_0.testnet")).build());
+        contract.withdraw();
+    }
+
+    #[test]
+    fn test_slash_operators_moves_the_slashed_fraction_to_treasury() {
+        let mut contract = new_test_contract();
+        let operator_account = account("operator_0.testnet");
+        contract.operator_accounts.insert(0, operator_account.clone());
+        contract.stakes.insert(operator_account.clone(), NearToken::from_yoctonear(1_000));
+
+        // new_test_contract() sets slash_fraction_bps to 1_000 (10%).
+        contract.slash_operators(&[0]);
+
+        assert_eq!(
+            contract.stakes.get(&operator_account).copied(),
+            Some(NearToken::from_yoctonear(900))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "UNAUTHORIZED")]
+    fn test_register_model_rejects_unprivileged_caller() {
+        let mut contract = new_test_contract();
+        testing_env!(get_context(account("random.testnet")).build());
+        contract.register_model(account("model.testnet"), "my_model".to_string(), NearToken::from_yoctonear(10));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_purge_expired_rejects_unauthorized_caller() {
+        let mut contract = new_test_contract();
+        testing_env!(get_context(account("random.testnet")).build());
+        contract.purge_expired(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "UNKNOWN_MODEL")]
+    fn test_before_task_submission_rejects_unknown_model() {
+        let mut contract = new_test_contract();
+        contract.register_operator(0, account("operator_0.testnet"), G2_GENERATOR.to_vec());
+
+        let inference_request = InferenceRequest {
+            model_name: "does_not_exist".to_string(),
+            prompt: "do the thing".to_string(),
+        };
+        let hash = message_hash(1, "proof", &inference_request.model_name, &inference_request.prompt);
+        let signature = AvsLogic::hash_to_g1(&hash);
+
+        contract.before_task_submission(
+            1,
+            account("performer.testnet"),
+            "proof".to_string(),
+            true,
+            signature.to_vec(),
+            [0, 0],
+            vec![0],
+            inference_request,
+        );
+    }
+
+    #[test]
+    fn test_return_external_response_resolves_sentinel_to_timeout() {
+        let mut contract = new_test_contract();
+        // #[private] methods may only be called by the contract itself.
+        let this_contract = account("avs.testnet");
+        let mut context = get_context(this_contract.clone());
+        context.current_account_id(this_contract);
+        testing_env!(context.build());
+
+        let response = contract.return_external_response(1, Ok(TIMEOUT_SENTINEL.to_string()));
+        assert!(matches!(response, Response::TimeOutError));
+    }
+
+    #[test]
+    #[should_panic(expected = "OPERATOR_SET_MISMATCH")]
+    fn test_after_task_submission_rejects_unverified_operator_set() {
+        let mut contract = new_test_contract();
+        contract.verified_operators.insert("proof".to_string(), vec![0]);
+
+        let model_info = ModelInfo { model_name: "my_model".to_string(), reward: NearToken::from_yoctonear(1) };
+        contract.after_task_submission(
+            1,
+            model_info,
+            "proof".to_string(),
+            true,
+            vec![],
+            [0, 0],
+            vec![1], // not the quorum recorded for "proof"
+        );
+    }
+}